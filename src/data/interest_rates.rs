@@ -1,11 +1,136 @@
+use crate::utils::thomas_solve;
+use std::cell::RefCell;
+
+/// How `interpolate_rate` fills in zero rates between the curve's pillar points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationMode {
+    /// Piecewise linear interpolation on the zero rates themselves.
+    Linear,
+    /// Linear interpolation of `ln(discount factor) = -r * t`, converted back to a rate.
+    LogLinearDiscount,
+    /// Natural cubic spline through the zero rates (C² continuous, M_0 = M_n = 0).
+    NaturalCubicSpline,
+}
+
 #[derive(Debug, Clone)]
 pub struct InterestRateCurve {
-    pub times: Vec<f64>,
-    pub rates: Vec<f64>,
+    times: Vec<f64>,
+    rates: Vec<f64>,
+    pub interpolation_mode: InterpolationMode,
+    spline_second_derivatives: RefCell<Option<Vec<f64>>>,
 }
 
 impl InterestRateCurve {
     pub fn new(times: Vec<f64>, rates: Vec<f64>) -> Self {
-        InterestRateCurve { times, rates }
+        InterestRateCurve {
+            times,
+            rates,
+            interpolation_mode: InterpolationMode::Linear,
+            spline_second_derivatives: RefCell::new(None),
+        }
+    }
+
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    pub fn rates(&self) -> &[f64] {
+        &self.rates
+    }
+
+    /// Replace the pillar times, invalidating the cached spline coefficients
+    /// (they were computed from the old times and would otherwise go stale).
+    pub fn set_times(&mut self, times: Vec<f64>) {
+        self.times = times;
+        *self.spline_second_derivatives.borrow_mut() = None;
+    }
+
+    /// Replace the pillar rates, invalidating the cached spline coefficients
+    /// (they were computed from the old rates and would otherwise go stale).
+    pub fn set_rates(&mut self, rates: Vec<f64>) {
+        self.rates = rates;
+        *self.spline_second_derivatives.borrow_mut() = None;
+    }
+
+    /// Second derivatives M_i of the natural cubic spline, computed once and cached.
+    pub(crate) fn spline_second_derivatives(&self) -> Vec<f64> {
+        if let Some(cached) = self.spline_second_derivatives.borrow().as_ref() {
+            return cached.clone();
+        }
+
+        let computed = natural_cubic_spline_second_derivatives(&self.times, &self.rates);
+        *self.spline_second_derivatives.borrow_mut() = Some(computed.clone());
+        computed
+    }
+
+    /// Parallel-shift every rate on the curve by `delta`, used to bump a flat
+    /// risk-free rate level when re-pricing for finite-difference Greeks.
+    pub(crate) fn shift(&self, delta: f64) -> InterestRateCurve {
+        let shifted_rates = self.rates.iter().map(|rate| rate + delta).collect();
+        let mut shifted = InterestRateCurve::new(self.times.clone(), shifted_rates);
+        shifted.interpolation_mode = self.interpolation_mode;
+        shifted
+    }
+}
+
+fn natural_cubic_spline_second_derivatives(times: &[f64], rates: &[f64]) -> Vec<f64> {
+    let n = times.len();
+    if n < 3 {
+        return vec![0.0; n];
+    }
+
+    let mut lower = vec![0.0; n];
+    let mut diag = vec![1.0; n];
+    let mut upper = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+
+    for i in 1..n - 1 {
+        let h_prev = times[i] - times[i - 1];
+        let h_next = times[i + 1] - times[i];
+
+        lower[i] = h_prev;
+        diag[i] = 2.0 * (h_prev + h_next);
+        upper[i] = h_next;
+        rhs[i] = 6.0 * ((rates[i + 1] - rates[i]) / h_next - (rates[i] - rates[i - 1]) / h_prev);
+    }
+
+    // The natural boundary conditions (diag[0] = diag[n-1] = 1) plus diagonal
+    // dominance on every interior row (diag[i] = 2*(h_prev+h_next) > h_prev+h_next)
+    // make this system provably non-singular.
+    thomas_solve(&lower, &diag, &upper, &rhs).expect("natural cubic spline system is never singular")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::interpolate_rate;
+
+    #[test]
+    fn linear_and_spline_interpolation_agree_on_pillar_points() {
+        let mut curve = InterestRateCurve::new(vec![0.0, 1.0, 2.0, 5.0], vec![0.02, 0.025, 0.03, 0.035]);
+
+        for &(time, rate) in &[(0.0, 0.02), (1.0, 0.025), (2.0, 0.03), (5.0, 0.035)] {
+            curve.interpolation_mode = InterpolationMode::Linear;
+            assert!((interpolate_rate(&curve, time).unwrap() - rate).abs() < 1e-9);
+
+            curve.interpolation_mode = InterpolationMode::NaturalCubicSpline;
+            assert!((interpolate_rate(&curve, time).unwrap() - rate).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn set_rates_invalidates_the_cached_spline_coefficients() {
+        let mut curve = InterestRateCurve::new(vec![0.0, 1.0, 2.0], vec![0.02, 0.025, 0.03]);
+        curve.interpolation_mode = InterpolationMode::NaturalCubicSpline;
+
+        let before = interpolate_rate(&curve, 0.5).unwrap();
+
+        curve.set_rates(vec![0.02, 0.1, 0.03]);
+        let after = interpolate_rate(&curve, 0.5).unwrap();
+
+        assert_ne!(
+            before, after,
+            "interpolated rate did not change after set_rates, spline cache was not invalidated"
+        );
     }
 }
@@ -0,0 +1,3 @@
+pub mod interest_rates;
+
+pub use interest_rates::{InterestRateCurve, InterpolationMode};
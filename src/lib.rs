@@ -6,12 +6,14 @@ pub mod models;
 pub mod regression;
 
 pub use errors::OptionPricingError;
-pub use traits::OptionPricingModel;
+pub use traits::{Greeks, GreeksModel, OptionPricingModel};
 pub use models::intrinsic_value::IntrinsicValue;
 pub use models::binomial::BinomialModel;
 pub use models::black_scholes::BlackScholesModel;
+pub use models::finite_difference::FiniteDifferenceModel;
 pub use models::heston::HestonModel;
-pub use data::interest_rates::InterestRateCurve;
+pub use data::interest_rates::{InterestRateCurve, InterpolationMode};
+pub use models::payoff::{BarrierDirection, PayoffKind};
 pub use regression::RegressionMethod;
 
 #[derive(Debug, Clone, Copy)]
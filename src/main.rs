@@ -1,7 +1,7 @@
 use option_pricing_lib::data::InterestRateCurve;
 use option_pricing_lib::models::heston::HestonModel;
-use option_pricing_lib::regression::RegressionMethod;
-use option_pricing_lib::traits::OptionPricingModel;
+use option_pricing_lib::models::payoff::PayoffKind;
+use option_pricing_lib::regression::{BasisFunction, RegressionMethod};
 use option_pricing_lib::OptionType;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -19,13 +19,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sigma: 0.1,
         rho: -0.7,
         is_american: true,
-        regression_method: RegressionMethod::LeastSquaresMonteCarlo,
+        regression_method: RegressionMethod::LeastSquaresMonteCarlo {
+            basis: BasisFunction::Laguerre,
+            degree: 2,
+        },
         num_paths: 10000,
         num_steps: 50,
+        payoff: PayoffKind::Vanilla,
+        antithetic: false,
+        control_variate: false,
     };
 
-    let price = heston_model.price()?;
-    println!("Heston Model American Option Price: {:.4}", price);
+    let (price, standard_error) = heston_model.price_with_standard_error()?;
+    println!("Heston Model American Option Price: {:.4} (stderr {:.4})", price, standard_error);
 
     Ok(())
 }
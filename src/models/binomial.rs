@@ -1,5 +1,6 @@
 use crate::errors::OptionPricingError;
-use crate::traits::OptionPricingModel;
+use crate::traits::greeks::finite_difference_greeks;
+use crate::traits::{Greeks, GreeksModel, OptionPricingModel};
 use crate::OptionType;
 
 pub struct BinomialModel {
@@ -53,10 +54,80 @@ impl OptionPricingModel for BinomialModel {
                     option_values[i] = option_values[i].max(exercise_value);
                 }
 
-                asset_prices[i] = asset_prices[i] * down / up;
+                asset_prices[i] = asset_prices[i] * down;
             }
         }
 
         Ok(option_values[0])
     }
 }
+
+impl GreeksModel for BinomialModel {
+    fn greeks(&self) -> Result<Greeks, OptionPricingError> {
+        finite_difference_greeks(
+            self.spot_price,
+            self.volatility,
+            self.risk_free_rate,
+            self.time_to_expiry,
+            |spot_price, volatility, risk_free_rate, time_to_expiry| {
+                BinomialModel {
+                    option_type: self.option_type,
+                    spot_price,
+                    strike_price: self.strike_price,
+                    time_to_expiry,
+                    volatility,
+                    risk_free_rate,
+                    steps: self.steps,
+                    is_american: self.is_american,
+                }
+                .price()
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::InterestRateCurve;
+    use crate::models::finite_difference::FiniteDifferenceModel;
+
+    // Pins the lattice reconstruction bug where `asset_prices[i] *= down / up`
+    // (i.e. `down^2`, since `up * down == 1`) instead of `down`, which made every
+    // American binomial price diverge toward the strike as `steps` grew. Cross-checks
+    // against the (independently implemented) Crank-Nicolson finite-difference pricer.
+    #[test]
+    fn american_put_price_tracks_finite_difference_model() {
+        let binomial = BinomialModel {
+            option_type: OptionType::Put,
+            spot_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+            steps: 500,
+            is_american: true,
+        };
+        let binomial_price = binomial.price().unwrap();
+
+        let finite_difference = FiniteDifferenceModel {
+            option_type: OptionType::Put,
+            spot_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            volatility: 0.2,
+            risk_free_rate_curve: InterestRateCurve::new(vec![0.0, 1.0], vec![0.05, 0.05]),
+            num_space: 200,
+            num_time: 200,
+            is_american: true,
+        };
+        let finite_difference_price = finite_difference.price().unwrap();
+
+        assert!(
+            (binomial_price - finite_difference_price).abs() < 0.1,
+            "binomial price {} diverged from finite-difference price {}",
+            binomial_price,
+            finite_difference_price
+        );
+    }
+}
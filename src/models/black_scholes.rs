@@ -1,6 +1,6 @@
 use crate::errors::OptionPricingError;
-use crate::traits::OptionPricingModel;
-use crate::utils::cumulative_normal_distribution;
+use crate::traits::{Greeks, GreeksModel, OptionPricingModel};
+use crate::utils::{cumulative_normal_distribution, standard_normal_pdf};
 use crate::OptionType;
 
 pub struct BlackScholesModel {
@@ -12,8 +12,8 @@ pub struct BlackScholesModel {
     pub risk_free_rate: f64,
 }
 
-impl OptionPricingModel for BlackScholesModel {
-    fn price(&self) -> Result<f64, OptionPricingError> {
+impl BlackScholesModel {
+    fn d1_d2(&self) -> Result<(f64, f64), OptionPricingError> {
         if self.time_to_expiry <= 0.0 {
             return Err(OptionPricingError::InvalidInput(
                 "Time to expiry must be positive.".to_string(),
@@ -26,6 +26,14 @@ impl OptionPricingModel for BlackScholesModel {
 
         let d2 = d1 - self.volatility * self.time_to_expiry.sqrt();
 
+        Ok((d1, d2))
+    }
+}
+
+impl OptionPricingModel for BlackScholesModel {
+    fn price(&self) -> Result<f64, OptionPricingError> {
+        let (d1, d2) = self.d1_d2()?;
+
         let nd1 = cumulative_normal_distribution(match self.option_type {
             OptionType::Call => d1,
             OptionType::Put => -d1,
@@ -46,3 +54,197 @@ impl OptionPricingModel for BlackScholesModel {
         Ok(price)
     }
 }
+
+impl GreeksModel for BlackScholesModel {
+    fn greeks(&self) -> Result<Greeks, OptionPricingError> {
+        let (d1, d2) = self.d1_d2()?;
+        let sqrt_t = self.time_to_expiry.sqrt();
+        let discount_factor = (-self.risk_free_rate * self.time_to_expiry).exp();
+        let pdf_d1 = standard_normal_pdf(d1);
+
+        let delta = match self.option_type {
+            OptionType::Call => cumulative_normal_distribution(d1),
+            OptionType::Put => cumulative_normal_distribution(d1) - 1.0,
+        };
+
+        let gamma = pdf_d1 / (self.spot_price * self.volatility * sqrt_t);
+        let vega = self.spot_price * pdf_d1 * sqrt_t;
+
+        let theta = match self.option_type {
+            OptionType::Call => {
+                -(self.spot_price * pdf_d1 * self.volatility) / (2.0 * sqrt_t)
+                    - self.risk_free_rate
+                        * self.strike_price
+                        * discount_factor
+                        * cumulative_normal_distribution(d2)
+            }
+            OptionType::Put => {
+                -(self.spot_price * pdf_d1 * self.volatility) / (2.0 * sqrt_t)
+                    + self.risk_free_rate
+                        * self.strike_price
+                        * discount_factor
+                        * cumulative_normal_distribution(-d2)
+            }
+        };
+
+        let rho = match self.option_type {
+            OptionType::Call => {
+                self.strike_price * self.time_to_expiry * discount_factor * cumulative_normal_distribution(d2)
+            }
+            OptionType::Put => {
+                -self.strike_price * self.time_to_expiry * discount_factor * cumulative_normal_distribution(-d2)
+            }
+        };
+
+        Ok(Greeks { delta, gamma, vega, theta, rho })
+    }
+}
+
+const IMPLIED_VOLATILITY_TOLERANCE: f64 = 1e-8;
+const IMPLIED_VOLATILITY_MAX_ITERATIONS: usize = 100;
+
+/// Recover the volatility that reprices `BlackScholesModel` to `market_price`.
+///
+/// Finds the root of f(sigma) = BlackScholes(sigma) - market_price with Brent's
+/// method: inverse quadratic interpolation when three distinct ordinates are
+/// available, falling back to the secant method, with a bisection safeguard
+/// (the classic "mflag" check) whenever the interpolated step is unreliable.
+pub fn implied_volatility(
+    market_price: f64,
+    option_type: OptionType,
+    spot_price: f64,
+    strike_price: f64,
+    time_to_expiry: f64,
+    risk_free_rate: f64,
+) -> Result<f64, OptionPricingError> {
+    let discount_factor = (-risk_free_rate * time_to_expiry).exp();
+    let (intrinsic_value, upper_bound) = match option_type {
+        OptionType::Call => ((spot_price - strike_price * discount_factor).max(0.0), spot_price),
+        OptionType::Put => ((strike_price * discount_factor - spot_price).max(0.0), strike_price * discount_factor),
+    };
+
+    if market_price < intrinsic_value - IMPLIED_VOLATILITY_TOLERANCE
+        || market_price > upper_bound + IMPLIED_VOLATILITY_TOLERANCE
+    {
+        return Err(OptionPricingError::ComputationError(
+            "Market price is outside the no-arbitrage bounds implied by the other inputs.".to_string(),
+        ));
+    }
+
+    let price_error = |volatility: f64| -> Result<f64, OptionPricingError> {
+        let model = BlackScholesModel {
+            option_type,
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            volatility,
+            risk_free_rate,
+        };
+        Ok(model.price()? - market_price)
+    };
+
+    let mut a = 1e-5_f64;
+    let mut b = 5.0_f64;
+    let mut fa = price_error(a)?;
+    let mut fb = price_error(b)?;
+
+    if fa * fb > 0.0 {
+        return Err(OptionPricingError::ComputationError(
+            "Could not bracket a root for the implied volatility search.".to_string(),
+        ));
+    }
+
+    if fa.abs() < fb.abs() {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = a;
+    let mut mflag = true;
+
+    for _ in 0..IMPLIED_VOLATILITY_MAX_ITERATIONS {
+        if fb.abs() < IMPLIED_VOLATILITY_TOLERANCE || (b - a).abs() < IMPLIED_VOLATILITY_TOLERANCE {
+            return Ok(b);
+        }
+
+        let mut s = if fa != fc && fb != fc {
+            a * fb * fc / ((fa - fb) * (fa - fc))
+                + b * fa * fc / ((fb - fa) * (fb - fc))
+                + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            b - fb * (b - a) / (fb - fa)
+        };
+
+        let needs_bisection = (s - b) * (s - (3.0 * a + b) / 4.0) > 0.0
+            || (mflag && (s - b).abs() >= (b - c).abs() / 2.0)
+            || (!mflag && (s - b).abs() >= (c - d).abs() / 2.0)
+            || (mflag && (b - c).abs() < IMPLIED_VOLATILITY_TOLERANCE)
+            || (!mflag && (c - d).abs() < IMPLIED_VOLATILITY_TOLERANCE);
+
+        if needs_bisection {
+            s = (a + b) / 2.0;
+            mflag = true;
+        } else {
+            mflag = false;
+        }
+
+        let fs = price_error(s)?;
+        d = c;
+        c = b;
+        fc = fb;
+
+        if fa * fs < 0.0 {
+            b = s;
+            fb = fs;
+        } else {
+            a = s;
+            fa = fs;
+        }
+
+        if fa.abs() < fb.abs() {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut fa, &mut fb);
+        }
+    }
+
+    Err(OptionPricingError::ComputationError(
+        "Implied volatility solver failed to converge.".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implied_volatility_round_trips_through_price() {
+        let model = BlackScholesModel {
+            option_type: OptionType::Call,
+            spot_price: 100.0,
+            strike_price: 105.0,
+            time_to_expiry: 0.75,
+            volatility: 0.27,
+            risk_free_rate: 0.03,
+        };
+        let market_price = model.price().unwrap();
+
+        let recovered_volatility = implied_volatility(
+            market_price,
+            model.option_type,
+            model.spot_price,
+            model.strike_price,
+            model.time_to_expiry,
+            model.risk_free_rate,
+        )
+        .unwrap();
+
+        assert!(
+            (recovered_volatility - model.volatility).abs() < 1e-6,
+            "recovered volatility {} did not round-trip to {}",
+            recovered_volatility,
+            model.volatility
+        );
+    }
+}
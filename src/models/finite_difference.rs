@@ -0,0 +1,207 @@
+use crate::data::InterestRateCurve;
+use crate::errors::OptionPricingError;
+use crate::traits::greeks::finite_difference_greeks;
+use crate::traits::{Greeks, GreeksModel, OptionPricingModel};
+use crate::utils::interpolate_rate;
+use crate::utils::thomas_solve;
+use crate::OptionType;
+
+/// Crank-Nicolson finite-difference pricer for the Black-Scholes PDE.
+///
+/// Grid-based analogue of `BinomialModel`: discretizes the asset axis on
+/// `[0, S_max]` with `num_space` steps and marches backward through
+/// `num_time` time steps, solving a tridiagonal system at each step with
+/// the Thomas algorithm. Early exercise for American options is enforced
+/// by projecting the solution onto the intrinsic payoff after each step.
+pub struct FiniteDifferenceModel {
+    pub option_type: OptionType,
+    pub spot_price: f64,
+    pub strike_price: f64,
+    pub time_to_expiry: f64,
+    pub volatility: f64,
+    pub risk_free_rate_curve: InterestRateCurve,
+    pub num_space: usize,
+    pub num_time: usize,
+    pub is_american: bool,
+}
+
+const ASSET_GRID_MULTIPLE: f64 = 4.0;
+
+impl OptionPricingModel for FiniteDifferenceModel {
+    fn price(&self) -> Result<f64, OptionPricingError> {
+        if self.num_space < 2 {
+            return Err(OptionPricingError::InvalidInput(
+                "Number of space steps must be at least two.".to_string(),
+            ));
+        }
+
+        if self.num_time == 0 {
+            return Err(OptionPricingError::InvalidInput(
+                "Number of time steps must be greater than zero.".to_string(),
+            ));
+        }
+
+        let s_max = ASSET_GRID_MULTIPLE * self.strike_price;
+        let ds = s_max / self.num_space as f64;
+        let dt = self.time_to_expiry / self.num_time as f64;
+
+        let asset_prices: Vec<f64> = (0..=self.num_space).map(|i| i as f64 * ds).collect();
+
+        let mut values: Vec<f64> = asset_prices
+            .iter()
+            .map(|&spot| intrinsic_payoff(self.option_type, spot, self.strike_price))
+            .collect();
+
+        let last = self.num_space;
+
+        // March backward from maturity (tau = 0) towards the valuation date.
+        for step in 0..self.num_time {
+            let tau = (step + 1) as f64 * dt;
+            let time = self.time_to_expiry - tau;
+            let rate = interpolate_rate(&self.risk_free_rate_curve, time)?;
+
+            let interior_count = last - 1;
+            let mut lower = vec![0.0; interior_count];
+            let mut diag = vec![0.0; interior_count];
+            let mut upper = vec![0.0; interior_count];
+            let mut rhs = vec![0.0; interior_count];
+
+            for k in 0..interior_count {
+                let i = (k + 1) as f64;
+                let alpha = 0.25 * dt * (self.volatility.powi(2) * i * i - rate * i);
+                let beta = -0.5 * dt * (self.volatility.powi(2) * i * i + rate);
+                let gamma = 0.25 * dt * (self.volatility.powi(2) * i * i + rate * i);
+
+                lower[k] = -alpha;
+                diag[k] = 1.0 - beta;
+                upper[k] = -gamma;
+                rhs[k] = alpha * values[k] + (1.0 + beta) * values[k + 1] + gamma * values[k + 2];
+            }
+
+            let (lower_boundary, upper_boundary) = match self.option_type {
+                OptionType::Call => (0.0, s_max - self.strike_price * (-rate * tau).exp()),
+                OptionType::Put => (self.strike_price * (-rate * tau).exp(), 0.0),
+            };
+
+            let alpha_first = 0.25 * dt * (self.volatility.powi(2) - rate);
+            rhs[0] += alpha_first * lower_boundary;
+
+            let i_last = interior_count as f64;
+            let gamma_last = 0.25 * dt * (self.volatility.powi(2) * i_last * i_last + rate * i_last);
+            rhs[interior_count - 1] += gamma_last * upper_boundary;
+
+            let interior = thomas_solve(&lower, &diag, &upper, &rhs)?;
+
+            values[0] = lower_boundary;
+            values[last] = upper_boundary;
+            values[1..last].copy_from_slice(&interior);
+
+            if self.is_american {
+                for (value, &spot) in values.iter_mut().zip(asset_prices.iter()) {
+                    let intrinsic = intrinsic_payoff(self.option_type, spot, self.strike_price);
+                    *value = value.max(intrinsic);
+                }
+            }
+        }
+
+        interpolate_grid_value(&asset_prices, &values, self.spot_price)
+    }
+}
+
+impl GreeksModel for FiniteDifferenceModel {
+    fn greeks(&self) -> Result<Greeks, OptionPricingError> {
+        let base_rate = interpolate_rate(&self.risk_free_rate_curve, self.time_to_expiry)?;
+
+        finite_difference_greeks(
+            self.spot_price,
+            self.volatility,
+            base_rate,
+            self.time_to_expiry,
+            |spot_price, volatility, risk_free_rate, time_to_expiry| {
+                FiniteDifferenceModel {
+                    option_type: self.option_type,
+                    spot_price,
+                    strike_price: self.strike_price,
+                    time_to_expiry,
+                    volatility,
+                    risk_free_rate_curve: self.risk_free_rate_curve.shift(risk_free_rate - base_rate),
+                    num_space: self.num_space,
+                    num_time: self.num_time,
+                    is_american: self.is_american,
+                }
+                .price()
+            },
+        )
+    }
+}
+
+fn intrinsic_payoff(option_type: OptionType, spot_price: f64, strike_price: f64) -> f64 {
+    match option_type {
+        OptionType::Call => (spot_price - strike_price).max(0.0),
+        OptionType::Put => (strike_price - spot_price).max(0.0),
+    }
+}
+
+/// Linearly interpolate the option value at `spot_price` from the priced grid.
+fn interpolate_grid_value(asset_prices: &[f64], values: &[f64], spot_price: f64) -> Result<f64, OptionPricingError> {
+    if spot_price <= asset_prices[0] {
+        return Ok(values[0]);
+    }
+
+    if spot_price >= asset_prices[asset_prices.len() - 1] {
+        return Ok(values[values.len() - 1]);
+    }
+
+    for i in 0..asset_prices.len() - 1 {
+        if spot_price >= asset_prices[i] && spot_price <= asset_prices[i + 1] {
+            let s0 = asset_prices[i];
+            let s1 = asset_prices[i + 1];
+            let v0 = values[i];
+            let v1 = values[i + 1];
+            return Ok(v0 + (v1 - v0) * (spot_price - s0) / (s1 - s0));
+        }
+    }
+
+    Err(OptionPricingError::InterpolationError(
+        "Failed to interpolate option value on the finite-difference grid.".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::black_scholes::BlackScholesModel;
+
+    #[test]
+    fn european_call_price_tracks_closed_form_black_scholes() {
+        let finite_difference = FiniteDifferenceModel {
+            option_type: OptionType::Call,
+            spot_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            volatility: 0.2,
+            risk_free_rate_curve: InterestRateCurve::new(vec![0.0, 1.0], vec![0.05, 0.05]),
+            num_space: 400,
+            num_time: 400,
+            is_american: false,
+        };
+        let finite_difference_price = finite_difference.price().unwrap();
+
+        let black_scholes = BlackScholesModel {
+            option_type: OptionType::Call,
+            spot_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            volatility: 0.2,
+            risk_free_rate: 0.05,
+        };
+        let black_scholes_price = black_scholes.price().unwrap();
+
+        assert!(
+            (finite_difference_price - black_scholes_price).abs() < 0.05,
+            "finite-difference price {} diverged from closed-form Black-Scholes price {}",
+            finite_difference_price,
+            black_scholes_price
+        );
+    }
+}
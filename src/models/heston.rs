@@ -1,15 +1,24 @@
 use crate::data::InterestRateCurve;
 use crate::errors::OptionPricingError;
+use crate::models::black_scholes::BlackScholesModel;
+use crate::models::payoff::PayoffKind;
 use crate::regression::{Regression, RegressionDataPoint, RegressionInput, RegressionMethod};
 use crate::regression::lsm::LeastSquaresMonteCarlo;
 use crate::regression::random_forest::RandomForestRegression;
-use crate::traits::OptionPricingModel;
+use crate::traits::greeks::finite_difference_greeks;
+use crate::traits::{Greeks, GreeksModel, OptionPricingModel};
 use crate::utils::interpolate_rate;
 use crate::OptionType;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rand_distr::StandardNormal;
 use std::rc::Rc;
 
+/// Fixed seed for the common-random-numbers draws used by `HestonModel::greeks`:
+/// the base price and all eight bumped repricings share these exact draws so the
+/// finite-difference Greeks reflect the bump, not independent Monte Carlo noise.
+const GREEKS_RNG_SEED: u64 = 0x4845_5354_4f4e;
+
 pub struct HestonModel {
     pub option_type: OptionType,
     pub spot_price: f64,
@@ -21,80 +30,235 @@ pub struct HestonModel {
     pub theta: f64,
     pub sigma: f64,
     pub rho: f64,
+    /// American exercise is only supported together with `payoff: PayoffKind::Vanilla`;
+    /// combining it with a path-dependent payoff is rejected in `price_with_standard_error`.
     pub is_american: bool,
     pub regression_method: RegressionMethod,
     pub num_paths: usize,
     pub num_steps: usize,
+    pub payoff: PayoffKind,
+    /// Pair each draw (z1, z2) with its mirrored draw (-z1, -z2) and average the two payoffs.
+    pub antithetic: bool,
+    /// For European payoffs, use the analytic Black-Scholes price as a control variate.
+    pub control_variate: bool,
 }
 
-impl OptionPricingModel for HestonModel {
-    fn price(&self) -> Result<f64, OptionPricingError> {
-        // Simulate asset paths
-        let mut paths = Vec::with_capacity(self.num_paths);
-        let dt = self.time_to_expiry / self.num_steps as f64;
-        let sqrt_dt = dt.sqrt();
+impl HestonModel {
+    /// Simulate one spot path from a pre-drawn sequence of correlated normal pairs,
+    /// so antithetic sampling can reuse the same draws negated.
+    fn simulate_path(&self, z_pairs: &[(f64, f64)], dt: f64, sqrt_dt: f64) -> Result<Vec<(f64, f64)>, OptionPricingError> {
+        let mut spot = self.spot_price;
+        let mut variance = self.initial_variance;
 
-        let mut rng = rand::thread_rng();
+        let mut path = Vec::with_capacity(self.num_steps + 1);
+        path.push((0.0, spot)); // (time, spot)
 
-        for _ in 0..self.num_paths {
-            let mut spot = self.spot_price;
-            let mut variance = self.initial_variance;
+        for (step, &(z1, z2)) in z_pairs.iter().enumerate() {
+            let w1 = z1;
+            let w2 = self.rho * z1 + (1.0 - self.rho.powi(2)).sqrt() * z2;
 
-            let mut path = Vec::with_capacity(self.num_steps + 1);
-            path.push((0.0, spot)); // (time, spot)
+            // Variance process
+            variance = (variance
+                + self.kappa * (self.theta - variance) * dt
+                + self.sigma * variance.sqrt() * w2 * sqrt_dt)
+                .max(0.0);
 
-            for step in 0..self.num_steps {
-                let z1: f64 = rng.sample(StandardNormal);
-                let z2: f64 = rng.sample(StandardNormal);
-                let w1 = z1;
-                let w2 = self.rho * z1 + (1.0 - self.rho.powi(2)).sqrt() * z2;
+            // Interest rate interpolation
+            let time = (step as f64 + 1.0) * dt;
+            let rate = interpolate_rate(&self.risk_free_rate_curve, time)?;
 
-                // Variance process
-                variance = (variance
-                    + self.kappa * (self.theta - variance) * dt
-                    + self.sigma * variance.sqrt() * w2 * sqrt_dt)
-                    .max(0.0);
+            // Asset price process
+            spot = spot * ((rate - 0.5 * variance) * dt + variance.sqrt() * w1 * sqrt_dt).exp();
 
-                // Interest rate interpolation
-                let time = (step as f64 + 1.0) * dt;
-                let rate = interpolate_rate(&self.risk_free_rate_curve, time)?;
+            path.push((time, spot));
+        }
 
-                // Asset price process
-                spot = spot * ((rate - 0.5 * variance) * dt + variance.sqrt() * w1 * sqrt_dt).exp();
+        Ok(path)
+    }
 
-                path.push((time, spot));
-            }
-            paths.push(path);
+    /// Simulate the terminal value of the constant-volatility GBM control used by
+    /// `control_variate_adjusted_payoffs`, driven by the *same* `w1` shocks as the
+    /// corresponding Heston path. Sharing shocks keeps the control correlated with
+    /// the actual path; using constant volatility instead of the path's own CIR
+    /// variance keeps it a genuinely different process, so the control payoff
+    /// never degenerates into the actual payoff (which would collapse the
+    /// variance-reduced estimate to the flat analytic Black-Scholes price).
+    fn simulate_control_terminal(
+        &self,
+        z_pairs: &[(f64, f64)],
+        dt: f64,
+        sqrt_dt: f64,
+        effective_volatility: f64,
+    ) -> Result<f64, OptionPricingError> {
+        let mut spot = self.spot_price;
+
+        for (step, &(z1, _z2)) in z_pairs.iter().enumerate() {
+            let time = (step as f64 + 1.0) * dt;
+            let rate = interpolate_rate(&self.risk_free_rate_curve, time)?;
+            spot = spot * ((rate - 0.5 * effective_volatility.powi(2)) * dt + effective_volatility * z1 * sqrt_dt).exp();
         }
 
-        if !self.is_american {
-            // Pricing European option
-            let mut payoffs = Vec::with_capacity(self.num_paths);
-
-            match self.option_type {
-                OptionType::Call => {
-                    for path in &paths {
-                        let spot = path[self.num_steps].1;
-                        payoffs.push((spot - self.strike_price).max(0.0));
-                    }
-                }
-                OptionType::Put => {
-                    for path in &paths {
-                        let spot = path[self.num_steps].1;
-                        payoffs.push((self.strike_price - spot).max(0.0));
-                    }
+        Ok(spot)
+    }
+
+    /// Control-variate adjustment of the per-path undiscounted payoffs: subtracts
+    /// the simulated-minus-analytic bias of a vanilla Black-Scholes control
+    /// (priced at an effective volatility) with the optimal coefficient
+    /// beta = Cov(payoff, control) / Var(control), applied path by path so the
+    /// adjusted sample can still be used to estimate a standard error.
+    fn control_variate_adjusted_payoffs(
+        &self,
+        z_pairs_per_path: &[Vec<(f64, f64)>],
+        payoffs: &[f64],
+        discount_factor: f64,
+        dt: f64,
+        sqrt_dt: f64,
+    ) -> Result<Vec<f64>, OptionPricingError> {
+        let effective_volatility = self.initial_variance.max(0.0).sqrt();
+        let rate = interpolate_rate(&self.risk_free_rate_curve, self.time_to_expiry)?;
+
+        let analytic_price = BlackScholesModel {
+            option_type: self.option_type,
+            spot_price: self.spot_price,
+            strike_price: self.strike_price,
+            time_to_expiry: self.time_to_expiry,
+            volatility: effective_volatility,
+            risk_free_rate: rate,
+        }
+        .price()?;
+        let analytic_mean_payoff = analytic_price / discount_factor;
+
+        let control_payoffs: Vec<f64> = z_pairs_per_path
+            .iter()
+            .map(|z_pairs| {
+                let terminal_spot = self.simulate_control_terminal(z_pairs, dt, sqrt_dt, effective_volatility)?;
+                Ok(match self.option_type {
+                    OptionType::Call => (terminal_spot - self.strike_price).max(0.0),
+                    OptionType::Put => (self.strike_price - terminal_spot).max(0.0),
+                })
+            })
+            .collect::<Result<Vec<f64>, OptionPricingError>>()?;
+
+        let n = payoffs.len() as f64;
+        let mean_payoff = payoffs.iter().sum::<f64>() / n;
+        let mean_control = control_payoffs.iter().sum::<f64>() / n;
+
+        let mut covariance = 0.0;
+        let mut variance = 0.0;
+        for i in 0..payoffs.len() {
+            let payoff_deviation = payoffs[i] - mean_payoff;
+            let control_deviation = control_payoffs[i] - mean_control;
+            covariance += payoff_deviation * control_deviation;
+            variance += control_deviation * control_deviation;
+        }
+
+        if variance.abs() < f64::EPSILON {
+            return Ok(payoffs.to_vec());
+        }
+
+        let beta = covariance / variance;
+        Ok(payoffs
+            .iter()
+            .zip(control_payoffs.iter())
+            .map(|(&payoff, &control)| payoff - beta * (control - analytic_mean_payoff))
+            .collect())
+    }
+
+    /// Draw the standard-normal pairs driving every simulated path, handling the
+    /// antithetic pairing (original draw immediately followed by its mirror).
+    /// Pulled out of `price_with_standard_error` so `greeks` can draw once from a
+    /// seeded RNG and reuse the exact same draws (common random numbers) across
+    /// the base price and every bumped repricing.
+    fn generate_z_pairs(&self, rng: &mut impl Rng) -> Vec<Vec<(f64, f64)>> {
+        let mut draw_pairs = || -> Vec<(f64, f64)> {
+            (0..self.num_steps)
+                .map(|_| (rng.sample(StandardNormal), rng.sample(StandardNormal)))
+                .collect()
+        };
+
+        let mut z_pairs_per_path = Vec::with_capacity(self.num_paths);
+
+        if self.antithetic {
+            let pair_count = (self.num_paths + 1) / 2;
+            for _ in 0..pair_count {
+                let z_pairs = draw_pairs();
+                z_pairs_per_path.push(z_pairs.clone());
+
+                if z_pairs_per_path.len() < self.num_paths {
+                    let mirrored_pairs: Vec<(f64, f64)> = z_pairs.iter().map(|&(z1, z2)| (-z1, -z2)).collect();
+                    z_pairs_per_path.push(mirrored_pairs);
                 }
             }
+        } else {
+            for _ in 0..self.num_paths {
+                z_pairs_per_path.push(draw_pairs());
+            }
+        }
+
+        z_pairs_per_path
+    }
+
+    /// Price the option together with the Monte Carlo standard error of the
+    /// final discounted estimate (sample standard deviation of per-path
+    /// discounted option values divided by sqrt(num_paths)). With `antithetic`
+    /// set, each mirrored pair is first averaged into one value, and the
+    /// standard error is taken over those pair averages.
+    pub fn price_with_standard_error(&self) -> Result<(f64, f64), OptionPricingError> {
+        let mut rng = rand::thread_rng();
+        let z_pairs_per_path = self.generate_z_pairs(&mut rng);
+        self.price_with_standard_error_from_draws(&z_pairs_per_path)
+    }
+
+    /// Core pricing logic given a pre-drawn set of standard-normal pairs for every
+    /// path (see `generate_z_pairs`), so the caller controls whether those draws
+    /// are fresh (`price_with_standard_error`) or fixed (`greeks`).
+    fn price_with_standard_error_from_draws(&self, z_pairs_per_path: &[Vec<(f64, f64)>]) -> Result<(f64, f64), OptionPricingError> {
+        // American exercise is only implemented against the plain vanilla payoff:
+        // the backward-induction loop below always compares the immediate vanilla
+        // exercise value to the regressed continuation value, which has no
+        // meaning for a path-dependent payoff (the "intrinsic value" of an Asian
+        // or barrier option part-way through a path isn't well-defined the same way).
+        if self.is_american && !matches!(self.payoff, PayoffKind::Vanilla) {
+            return Err(OptionPricingError::InvalidInput(
+                "American exercise is only supported for PayoffKind::Vanilla.".to_string(),
+            ));
+        }
+
+        let dt = self.time_to_expiry / self.num_steps as f64;
+        let sqrt_dt = dt.sqrt();
+
+        let paths: Vec<Vec<(f64, f64)>> = z_pairs_per_path
+            .iter()
+            .map(|z_pairs| self.simulate_path(z_pairs, dt, sqrt_dt))
+            .collect::<Result<_, _>>()?;
+
+        if !self.is_american {
+            // Pricing European (possibly path-dependent) option
+            let payoffs: Vec<f64> = paths
+                .iter()
+                .map(|path| self.payoff.evaluate(path, self.option_type, self.strike_price))
+                .collect();
 
-            let average_payoff: f64 = payoffs.iter().sum::<f64>() / self.num_paths as f64;
             let rate = interpolate_rate(&self.risk_free_rate_curve, self.time_to_expiry)?;
-            let discounted_payoff = average_payoff * (-rate * self.time_to_expiry).exp();
-            return Ok(discounted_payoff);
+            let discount_factor = (-rate * self.time_to_expiry).exp();
+
+            let estimates = if self.control_variate {
+                self.control_variate_adjusted_payoffs(z_pairs_per_path, &payoffs, discount_factor, dt, sqrt_dt)?
+            } else {
+                payoffs
+            };
+
+            let (mean, standard_error) = mean_and_standard_error(&estimates, self.antithetic);
+            return Ok((mean * discount_factor, standard_error * discount_factor));
         }
 
         // For American options, use regression methods
-        let regression: Rc<dyn Regression> = match self.regression_method {
-            RegressionMethod::LeastSquaresMonteCarlo => Rc::new(LeastSquaresMonteCarlo {}),
+        let regression: Rc<dyn Regression> = match &self.regression_method {
+            RegressionMethod::LeastSquaresMonteCarlo { basis, degree } => Rc::new(LeastSquaresMonteCarlo {
+                basis: *basis,
+                degree: *degree,
+                strike_price: self.strike_price,
+            }),
             RegressionMethod::RandomForest => Rc::new(RandomForestRegression {}),
         };
 
@@ -180,13 +344,171 @@ impl OptionPricingModel for HestonModel {
         }
 
         // Discount option values to present value
-        let mut price = 0.0;
-        for i in 0..self.num_paths {
-            let rate = interpolate_rate(&self.risk_free_rate_curve, 0.0)?;
-            price += option_values[i] * (-rate * dt).exp();
+        let rate = interpolate_rate(&self.risk_free_rate_curve, 0.0)?;
+        let discount_factor = (-rate * dt).exp();
+        let final_values: Vec<f64> = option_values.iter().map(|value| value * discount_factor).collect();
+
+        Ok(mean_and_standard_error(&final_values, self.antithetic))
+    }
+}
+
+impl OptionPricingModel for HestonModel {
+    fn price(&self) -> Result<f64, OptionPricingError> {
+        self.price_with_standard_error().map(|(price, _standard_error)| price)
+    }
+}
+
+/// Sample mean and standard error (sample standard deviation / sqrt(n)) of `values`.
+fn sample_mean_and_standard_error(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+
+    if values.len() < 2 {
+        return (mean, 0.0);
+    }
+
+    let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, (variance / n).sqrt())
+}
+
+/// Mean and standard error of `values`, where `paired` indicates that consecutive
+/// entries are antithetic (negatively correlated) partners rather than i.i.d.
+/// draws: each pair is averaged into a single value first, since pooling
+/// correlated draws directly into `sample_mean_and_standard_error` understates
+/// or overstates the true standard error of the paired estimator.
+fn mean_and_standard_error(values: &[f64], paired: bool) -> (f64, f64) {
+    if !paired {
+        return sample_mean_and_standard_error(values);
+    }
+
+    let pair_means: Vec<f64> = values
+        .chunks(2)
+        .map(|pair| pair.iter().sum::<f64>() / pair.len() as f64)
+        .collect();
+
+    sample_mean_and_standard_error(&pair_means)
+}
+
+impl GreeksModel for HestonModel {
+    fn greeks(&self) -> Result<Greeks, OptionPricingError> {
+        let base_rate = interpolate_rate(&self.risk_free_rate_curve, self.time_to_expiry)?;
+        let base_volatility = self.initial_variance.max(0.0).sqrt();
+
+        // Common random numbers: draw the standard-normal pairs once from a seeded
+        // RNG and reuse them for the base price and every bumped repricing below.
+        // Independent `thread_rng()` draws per repricing would swamp these tiny
+        // bump sizes in Monte Carlo sampling noise, making the Greeks unusable.
+        let mut rng = StdRng::seed_from_u64(GREEKS_RNG_SEED);
+        let z_pairs_per_path = self.generate_z_pairs(&mut rng);
+
+        finite_difference_greeks(
+            self.spot_price,
+            base_volatility,
+            base_rate,
+            self.time_to_expiry,
+            |spot_price, volatility, risk_free_rate, time_to_expiry| {
+                let regression_method = match &self.regression_method {
+                    RegressionMethod::LeastSquaresMonteCarlo { basis, degree } => {
+                        RegressionMethod::LeastSquaresMonteCarlo { basis: *basis, degree: *degree }
+                    }
+                    RegressionMethod::RandomForest => RegressionMethod::RandomForest,
+                };
+
+                HestonModel {
+                    option_type: self.option_type,
+                    spot_price,
+                    strike_price: self.strike_price,
+                    time_to_expiry,
+                    initial_variance: volatility.powi(2),
+                    risk_free_rate_curve: self.risk_free_rate_curve.shift(risk_free_rate - base_rate),
+                    kappa: self.kappa,
+                    theta: self.theta,
+                    sigma: self.sigma,
+                    rho: self.rho,
+                    is_american: self.is_american,
+                    regression_method,
+                    num_paths: self.num_paths,
+                    num_steps: self.num_steps,
+                    payoff: self.payoff,
+                    antithetic: self.antithetic,
+                    control_variate: self.control_variate,
+                }
+                .price_with_standard_error_from_draws(&z_pairs_per_path)
+                .map(|(price, _standard_error)| price)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regression::BasisFunction;
+
+    fn atm_call() -> HestonModel {
+        HestonModel {
+            option_type: OptionType::Call,
+            spot_price: 100.0,
+            strike_price: 100.0,
+            time_to_expiry: 1.0,
+            initial_variance: 0.09,
+            risk_free_rate_curve: InterestRateCurve::new(vec![0.0, 1.0], vec![0.03, 0.03]),
+            kappa: 2.0,
+            theta: 0.09,
+            sigma: 0.3,
+            rho: -0.7,
+            is_american: false,
+            regression_method: RegressionMethod::LeastSquaresMonteCarlo { basis: BasisFunction::Monomial, degree: 2 },
+            num_paths: 4000,
+            num_steps: 50,
+            payoff: PayoffKind::Vanilla,
+            antithetic: false,
+            control_variate: false,
+        }
+    }
+
+    // Common random numbers must keep a call's finite-difference delta inside the
+    // model-free [0, 1] bound; independent Monte Carlo draws per bump previously
+    // let it swing outside that range.
+    #[test]
+    fn heston_call_delta_is_within_model_free_bounds() {
+        let greeks = atm_call().greeks().unwrap();
+        assert!(
+            (0.0..=1.0).contains(&greeks.delta),
+            "call delta {} is outside [0, 1]",
+            greeks.delta
+        );
+    }
+
+    // The control-variate control must be a genuinely different process from the
+    // priced payoff: it previously collapsed to the exact vanilla payoff, which
+    // made every control-variate price equal the flat analytic Black-Scholes
+    // price (stderr exactly 0.0) regardless of kappa/theta/sigma/rho.
+    #[test]
+    fn control_variate_price_is_not_the_flat_analytic_price() {
+        let mut model = atm_call();
+        model.num_paths = 20000;
+        model.control_variate = true;
+
+        let (price, standard_error) = model.price_with_standard_error().unwrap();
+
+        let analytic_price = BlackScholesModel {
+            option_type: model.option_type,
+            spot_price: model.spot_price,
+            strike_price: model.strike_price,
+            time_to_expiry: model.time_to_expiry,
+            volatility: model.initial_variance.sqrt(),
+            risk_free_rate: interpolate_rate(&model.risk_free_rate_curve, model.time_to_expiry).unwrap(),
         }
-        price /= self.num_paths as f64;
+        .price()
+        .unwrap();
 
-        Ok(price)
+        assert!(standard_error > 0.0, "control-variate stderr collapsed to exactly 0.0");
+        assert!(
+            (price - analytic_price).abs() > 1e-6,
+            "control-variate price {} collapsed to the flat analytic price {}",
+            price,
+            analytic_price
+        );
     }
 }
@@ -1,9 +1,13 @@
 pub mod intrinsic_value;
 pub mod binomial;
 pub mod black_scholes;
+pub mod finite_difference;
 pub mod heston;
+pub mod payoff;
 
 pub use intrinsic_value::IntrinsicValue;
 pub use binomial::BinomialModel;
-pub use black_scholes::BlackScholesModel;
+pub use black_scholes::{implied_volatility, BlackScholesModel};
+pub use finite_difference::FiniteDifferenceModel;
 pub use heston::HestonModel;
+pub use payoff::{BarrierDirection, PayoffKind};
@@ -2,22 +2,35 @@ use crate::errors::OptionPricingError;
 use crate::regression::{Regression, RegressionDataPoint, RegressionInput, RegressionModel};
 use ndarray::Array2;
 use ndarray_linalg::LeastSquaresSvd;
-use std::collections::HashMap;
 
-pub struct LeastSquaresMonteCarlo {}
+/// Basis functions used to regress continuation value against asset price.
+#[derive(Debug, Clone, Copy)]
+pub enum BasisFunction {
+    /// Raw monomials in the asset price: 1, S, S^2, ...
+    Monomial,
+    /// Laguerre polynomials in the scaled moneyness S/K, the basis used in the
+    /// original Longstaff-Schwartz paper and better conditioned than raw powers.
+    Laguerre,
+}
+
+pub struct LeastSquaresMonteCarlo {
+    pub basis: BasisFunction,
+    pub degree: usize,
+    pub strike_price: f64,
+}
 
 impl Regression for LeastSquaresMonteCarlo {
     fn fit(&self, data: &[RegressionDataPoint]) -> Result<Box<dyn RegressionModel>, OptionPricingError> {
         let n = data.len();
-        let degree = 2; // Degree of polynomial basis functions
+        let num_terms = self.degree + 1;
 
-        let mut x = Array2::<f64>::zeros((n, degree + 1));
+        let mut x = Array2::<f64>::zeros((n, num_terms));
         let mut y = Array2::<f64>::zeros((n, 1));
 
         for (i, point) in data.iter().enumerate() {
-            let s = point.asset_price;
-            for j in 0..=degree {
-                x[[i, j]] = s.powi(j as i32);
+            let terms = basis_terms(self.basis, self.degree, point.asset_price, self.strike_price);
+            for (j, term) in terms.into_iter().enumerate() {
+                x[[i, j]] = term;
             }
             y[[i, 0]] = point.continuation_value;
         }
@@ -27,23 +40,71 @@ impl Regression for LeastSquaresMonteCarlo {
             OptionPricingError::RegressionError(format!("LSM regression failed: {:?}", e))
         })?;
 
-        let coeffs = result.solution.column(0).to_owned().to_vec();
+        let coefficients = result.solution.column(0).to_owned().to_vec();
 
-        Ok(Box::new(LSMModel { coefficients: coeffs }))
+        Ok(Box::new(LSMModel {
+            basis: self.basis,
+            degree: self.degree,
+            strike_price: self.strike_price,
+            coefficients,
+        }))
     }
 }
 
 pub struct LSMModel {
+    pub basis: BasisFunction,
+    pub degree: usize,
+    pub strike_price: f64,
     pub coefficients: Vec<f64>,
 }
 
 impl RegressionModel for LSMModel {
     fn predict(&self, input: &RegressionInput) -> f64 {
-        let s = input.asset_price;
-        let mut value = 0.0;
-        for (i, &coeff) in self.coefficients.iter().enumerate() {
-            value += coeff * s.powi(i as i32);
+        let terms = basis_terms(self.basis, self.degree, input.asset_price, self.strike_price);
+        self.coefficients.iter().zip(terms.iter()).map(|(coeff, term)| coeff * term).sum()
+    }
+}
+
+fn basis_terms(basis: BasisFunction, degree: usize, asset_price: f64, strike_price: f64) -> Vec<f64> {
+    match basis {
+        BasisFunction::Monomial => (0..=degree).map(|j| asset_price.powi(j as i32)).collect(),
+        BasisFunction::Laguerre => laguerre_polynomials(degree, asset_price / strike_price),
+    }
+}
+
+/// L_0(x) = 1, L_1(x) = 1 - x, (n+1) L_{n+1}(x) = (2n + 1 - x) L_n(x) - n L_{n-1}(x).
+fn laguerre_polynomials(degree: usize, x: f64) -> Vec<f64> {
+    let mut values = Vec::with_capacity(degree + 1);
+    values.push(1.0);
+
+    if degree >= 1 {
+        values.push(1.0 - x);
+    }
+
+    for n in 1..degree {
+        let next = ((2.0 * n as f64 + 1.0 - x) * values[n] - n as f64 * values[n - 1]) / (n as f64 + 1.0);
+        values.push(next);
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hand-computed against the closed-form Laguerre polynomials L_0..L_3(x) =
+    // 1, 1-x, (x^2-4x+2)/2, (-x^3+9x^2-18x+6)/6, evaluated at x = 2.
+    #[test]
+    fn laguerre_polynomials_match_hand_computed_values() {
+        let x = 2.0;
+        let values = laguerre_polynomials(3, x);
+
+        let expected = [1.0, 1.0 - x, (x.powi(2) - 4.0 * x + 2.0) / 2.0, (-x.powi(3) + 9.0 * x.powi(2) - 18.0 * x + 6.0) / 6.0];
+
+        assert_eq!(values.len(), expected.len());
+        for (value, expected_value) in values.iter().zip(expected.iter()) {
+            assert!((value - expected_value).abs() < 1e-9, "{} != {}", value, expected_value);
         }
-        value
     }
 }
@@ -1,13 +1,13 @@
 pub mod lsm;
 pub mod random_forest;
 
-pub use lsm::LeastSquaresMonteCarlo;
+pub use lsm::{BasisFunction, LeastSquaresMonteCarlo};
 pub use random_forest::RandomForestRegression;
 
 use crate::errors::OptionPricingError;
 
 pub enum RegressionMethod {
-    LeastSquaresMonteCarlo,
+    LeastSquaresMonteCarlo { basis: BasisFunction, degree: usize },
     RandomForest,
 }
 
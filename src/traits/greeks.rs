@@ -0,0 +1,56 @@
+use crate::errors::OptionPricingError;
+
+/// Price sensitivities ("the Greeks") with respect to spot, volatility, time and rate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+}
+
+pub trait GreeksModel {
+    fn greeks(&self) -> Result<Greeks, OptionPricingError>;
+}
+
+const SPOT_BUMP_RELATIVE: f64 = 1e-3;
+const VOLATILITY_BUMP: f64 = 1e-4;
+const RATE_BUMP: f64 = 1e-4;
+const TIME_BUMP_RELATIVE: f64 = 1e-4;
+
+/// Generic central-difference Greeks for models that have no closed form.
+///
+/// `reprice` re-runs the model's own pricer with the given `spot`, `volatility`,
+/// `risk_free_rate` and `time_to_expiry`, so every lattice/Monte Carlo model can
+/// share the same bump-and-reprice logic instead of re-deriving it.
+pub fn finite_difference_greeks(
+    spot_price: f64,
+    volatility: f64,
+    risk_free_rate: f64,
+    time_to_expiry: f64,
+    reprice: impl Fn(f64, f64, f64, f64) -> Result<f64, OptionPricingError>,
+) -> Result<Greeks, OptionPricingError> {
+    let price = reprice(spot_price, volatility, risk_free_rate, time_to_expiry)?;
+
+    let h_spot = spot_price * SPOT_BUMP_RELATIVE;
+    let price_spot_up = reprice(spot_price + h_spot, volatility, risk_free_rate, time_to_expiry)?;
+    let price_spot_down = reprice(spot_price - h_spot, volatility, risk_free_rate, time_to_expiry)?;
+    let delta = (price_spot_up - price_spot_down) / (2.0 * h_spot);
+    let gamma = (price_spot_up - 2.0 * price + price_spot_down) / (h_spot * h_spot);
+
+    let price_vol_up = reprice(spot_price, volatility + VOLATILITY_BUMP, risk_free_rate, time_to_expiry)?;
+    let price_vol_down = reprice(spot_price, volatility - VOLATILITY_BUMP, risk_free_rate, time_to_expiry)?;
+    let vega = (price_vol_up - price_vol_down) / (2.0 * VOLATILITY_BUMP);
+
+    let price_rate_up = reprice(spot_price, volatility, risk_free_rate + RATE_BUMP, time_to_expiry)?;
+    let price_rate_down = reprice(spot_price, volatility, risk_free_rate - RATE_BUMP, time_to_expiry)?;
+    let rho = (price_rate_up - price_rate_down) / (2.0 * RATE_BUMP);
+
+    let h_time = (time_to_expiry * TIME_BUMP_RELATIVE).max(1e-6);
+    let price_time_up = reprice(spot_price, volatility, risk_free_rate, time_to_expiry + h_time)?;
+    let price_time_down = reprice(spot_price, volatility, risk_free_rate, time_to_expiry - h_time)?;
+    let theta = -(price_time_up - price_time_down) / (2.0 * h_time);
+
+    Ok(Greeks { delta, gamma, vega, theta, rho })
+}
@@ -0,0 +1,5 @@
+pub mod greeks;
+pub mod option_pricing;
+
+pub use greeks::{Greeks, GreeksModel};
+pub use option_pricing::OptionPricingModel;
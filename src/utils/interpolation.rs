@@ -1,9 +1,10 @@
+use crate::data::interest_rates::InterpolationMode;
 use crate::data::InterestRateCurve;
 use crate::errors::OptionPricingError;
 
 pub fn interpolate_rate(curve: &InterestRateCurve, time: f64) -> Result<f64, OptionPricingError> {
-    let times = &curve.times;
-    let rates = &curve.rates;
+    let times = curve.times();
+    let rates = curve.rates();
 
     if times.is_empty() || rates.is_empty() || times.len() != rates.len() {
         return Err(OptionPricingError::InvalidInput(
@@ -19,6 +20,14 @@ pub fn interpolate_rate(curve: &InterestRateCurve, time: f64) -> Result<f64, Opt
         return Ok(rates[rates.len() - 1]);
     }
 
+    match curve.interpolation_mode {
+        InterpolationMode::Linear => interpolate_linear(times, rates, time),
+        InterpolationMode::LogLinearDiscount => interpolate_log_linear_discount(times, rates, time),
+        InterpolationMode::NaturalCubicSpline => interpolate_natural_cubic_spline(curve, time),
+    }
+}
+
+fn interpolate_linear(times: &[f64], rates: &[f64], time: f64) -> Result<f64, OptionPricingError> {
     for i in 0..times.len() - 1 {
         if time >= times[i] && time <= times[i + 1] {
             let t0 = times[i];
@@ -35,3 +44,48 @@ pub fn interpolate_rate(curve: &InterestRateCurve, time: f64) -> Result<f64, Opt
         "Failed to interpolate rate.".to_string(),
     ))
 }
+
+fn interpolate_log_linear_discount(times: &[f64], rates: &[f64], time: f64) -> Result<f64, OptionPricingError> {
+    for i in 0..times.len() - 1 {
+        if time >= times[i] && time <= times[i + 1] {
+            let t0 = times[i];
+            let t1 = times[i + 1];
+            let log_discount_0 = -rates[i] * t0;
+            let log_discount_1 = -rates[i + 1] * t1;
+
+            let log_discount = log_discount_0 + (log_discount_1 - log_discount_0) * (time - t0) / (t1 - t0);
+            return Ok(-log_discount / time);
+        }
+    }
+
+    Err(OptionPricingError::InterpolationError(
+        "Failed to interpolate rate.".to_string(),
+    ))
+}
+
+fn interpolate_natural_cubic_spline(curve: &InterestRateCurve, time: f64) -> Result<f64, OptionPricingError> {
+    let times = curve.times();
+    let rates = curve.rates();
+    let second_derivatives = curve.spline_second_derivatives();
+
+    for i in 0..times.len() - 1 {
+        if time >= times[i] && time <= times[i + 1] {
+            let t0 = times[i];
+            let t1 = times[i + 1];
+            let h = t1 - t0;
+            let m0 = second_derivatives[i];
+            let m1 = second_derivatives[i + 1];
+
+            let a = (t1 - time).powi(3) * m0 / (6.0 * h);
+            let b = (time - t0).powi(3) * m1 / (6.0 * h);
+            let c = (rates[i] / h - m0 * h / 6.0) * (t1 - time);
+            let d = (rates[i + 1] / h - m1 * h / 6.0) * (time - t0);
+
+            return Ok(a + b + c + d);
+        }
+    }
+
+    Err(OptionPricingError::InterpolationError(
+        "Failed to interpolate rate.".to_string(),
+    ))
+}
@@ -0,0 +1,39 @@
+use crate::errors::OptionPricingError;
+
+/// Solve the tridiagonal system `lower[i]*x[i-1] + diag[i]*x[i] + upper[i]*x[i+1] = rhs[i]`
+/// with the Thomas algorithm (`lower[0]` and `upper[n-1]` are ignored). Shared by the
+/// Crank-Nicolson PDE pricer and the natural cubic spline coefficient solver.
+pub(crate) fn thomas_solve(lower: &[f64], diag: &[f64], upper: &[f64], rhs: &[f64]) -> Result<Vec<f64>, OptionPricingError> {
+    let n = diag.len();
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+
+    if diag[0] == 0.0 {
+        return Err(OptionPricingError::ComputationError(
+            "Tridiagonal system is singular.".to_string(),
+        ));
+    }
+    c_prime[0] = upper[0] / diag[0];
+    d_prime[0] = rhs[0] / diag[0];
+
+    for i in 1..n {
+        let denominator = diag[i] - lower[i] * c_prime[i - 1];
+        if denominator == 0.0 {
+            return Err(OptionPricingError::ComputationError(
+                "Tridiagonal system is singular.".to_string(),
+            ));
+        }
+        if i < n - 1 {
+            c_prime[i] = upper[i] / denominator;
+        }
+        d_prime[i] = (rhs[i] - lower[i] * d_prime[i - 1]) / denominator;
+    }
+
+    let mut solution = vec![0.0; n];
+    solution[n - 1] = d_prime[n - 1];
+    for i in (0..n - 1).rev() {
+        solution[i] = d_prime[i] - c_prime[i] * solution[i + 1];
+    }
+
+    Ok(solution)
+}
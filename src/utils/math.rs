@@ -1,6 +1,11 @@
-use statrs::distribution::{ContinuousCDF, Normal};
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
 
 pub fn cumulative_normal_distribution(x: f64) -> f64 {
     let normal = Normal::new(0.0, 1.0).unwrap();
     normal.cdf(x)
 }
+
+pub fn standard_normal_pdf(x: f64) -> f64 {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    normal.pdf(x)
+}
@@ -1,5 +1,7 @@
 pub mod math;
 pub mod interpolation;
+pub(crate) mod linalg;
 
-pub use math::cumulative_normal_distribution;
+pub use math::{cumulative_normal_distribution, standard_normal_pdf};
 pub use interpolation::interpolate_rate;
+pub(crate) use linalg::thomas_solve;